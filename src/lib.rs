@@ -10,7 +10,7 @@
 //! assert_eq!(&slice[1], &[3, 4]);
 //! ```
 #![no_std]
-use core::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut, Range};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A 2 dimensional slice.
@@ -34,6 +34,123 @@ impl<T> Slice2D<'_, T> {
         let origin = index * self.stride;
         self.slice.get(origin..origin + self.len)
     }
+
+    /// Returns a reference to a subslice, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be in bounds.
+    pub unsafe fn get_unchecked(&self, row: usize) -> &[T] {
+        let origin = row * self.stride;
+        unsafe { self.slice.get_unchecked(origin..origin + self.len) }
+    }
+
+    /// Returns a reference to a single element, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` and `col` must be in bounds.
+    pub unsafe fn get_unchecked_elem(&self, row: usize, col: usize) -> &T {
+        unsafe { self.slice.get_unchecked(row * self.stride + col) }
+    }
+
+    /// Returns the number of complete rows, following the same "incomplete rows discarded" rule
+    /// as iteration.
+    fn num_rows(&self) -> usize {
+        if self.stride == 0 || self.slice.len() < self.len {
+            0
+        } else {
+            (self.slice.len() - self.len) / self.stride + 1
+        }
+    }
+}
+
+impl<'t, T> Slice2D<'t, T> {
+    /// Returns a lazy iterator over column `j`, striding down the rows.
+    pub fn column(&self, j: usize) -> ColumnIter<'t, T> {
+        let remaining = if j < self.len { self.num_rows() } else { 0 };
+        ColumnIter {
+            slice: self.slice.get(j..).unwrap_or(&[]),
+            stride: self.stride,
+            remaining,
+        }
+    }
+
+    /// Returns an iterator over all columns, each yielding a [`ColumnIter`].
+    pub fn columns(&self) -> Columns<'t, T> {
+        Columns {
+            slice: self.slice,
+            stride: self.stride,
+            rows: self.num_rows(),
+            len: self.len,
+            col: 0,
+        }
+    }
+
+    /// Returns a view over the rectangular sub-region spanned by `rows` and `cols`.
+    ///
+    /// The original `stride` is kept so rows of the crop remain addressable into the backing
+    /// slice.
+    pub fn crop(&self, rows: Range<usize>, cols: Range<usize>) -> Slice2D<'t, T> {
+        let start = rows.start * self.stride + cols.start;
+        let len = cols.end - cols.start;
+        let end = match rows.end.checked_sub(rows.start) {
+            Some(0) | None => start,
+            Some(num_rows) => start + (num_rows - 1) * self.stride + len,
+        };
+        Slice2D {
+            stride: self.stride,
+            len,
+            slice: &self.slice[start..end],
+        }
+    }
+}
+
+/// A lazy iterator down a single column, created by [`Slice2D::column`].
+pub struct ColumnIter<'t, T> {
+    slice: &'t [T],
+    stride: usize,
+    remaining: usize,
+}
+
+impl<'t, T> Iterator for ColumnIter<'t, T> {
+    type Item = &'t T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let item = self.slice.first();
+        self.slice = self.slice.get(self.stride..).unwrap_or(&[]);
+        item
+    }
+}
+
+/// An iterator over all columns, created by [`Slice2D::columns`].
+pub struct Columns<'t, T> {
+    slice: &'t [T],
+    stride: usize,
+    rows: usize,
+    len: usize,
+    col: usize,
+}
+
+impl<'t, T> Iterator for Columns<'t, T> {
+    type Item = ColumnIter<'t, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col >= self.len {
+            return None;
+        }
+        let iter = ColumnIter {
+            slice: self.slice.get(self.col..).unwrap_or(&[]),
+            stride: self.stride,
+            remaining: self.rows,
+        };
+        self.col += 1;
+        Some(iter)
+    }
 }
 
 impl<'t, T> Slice2DMut<'t, T> {
@@ -61,11 +178,295 @@ impl<'t, T> Slice2DMut<'t, T> {
         self.slice.get(origin..origin + self.len)
     }
 
+    /// Returns a mutable view over the rectangular sub-region spanned by `rows` and `cols`.
+    ///
+    /// The original `stride` is kept so rows of the crop remain addressable into the backing
+    /// slice.
+    pub fn crop_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> Slice2DMut<'_, T> {
+        let start = rows.start * self.stride + cols.start;
+        let len = cols.end - cols.start;
+        let end = match rows.end.checked_sub(rows.start) {
+            Some(0) | None => start,
+            Some(num_rows) => start + (num_rows - 1) * self.stride + len,
+        };
+        Slice2DMut {
+            stride: self.stride,
+            len,
+            slice: &mut self.slice[start..end],
+        }
+    }
+
     /// Returns a mutable reference to a subslice.
     pub fn get_mut(&mut self, index: usize) -> Option<&mut [T]> {
         let origin = index * self.stride;
         self.slice.get_mut(origin..origin + self.len)
     }
+
+    /// Returns a reference to a subslice, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be in bounds.
+    pub unsafe fn get_unchecked(&self, row: usize) -> &[T] {
+        let origin = row * self.stride;
+        unsafe { self.slice.get_unchecked(origin..origin + self.len) }
+    }
+
+    /// Returns a mutable reference to a subslice, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be in bounds.
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize) -> &mut [T] {
+        let origin = row * self.stride;
+        unsafe { self.slice.get_unchecked_mut(origin..origin + self.len) }
+    }
+
+    /// Returns a reference to a single element, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` and `col` must be in bounds.
+    pub unsafe fn get_unchecked_elem(&self, row: usize, col: usize) -> &T {
+        unsafe { self.slice.get_unchecked(row * self.stride + col) }
+    }
+
+    /// Returns a mutable reference to a single element, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `row` and `col` must be in bounds.
+    pub unsafe fn get_unchecked_elem_mut(&mut self, row: usize, col: usize) -> &mut T {
+        unsafe { self.slice.get_unchecked_mut(row * self.stride + col) }
+    }
+
+    /// Returns the number of complete rows, following the same "incomplete rows discarded" rule
+    /// as iteration.
+    fn num_rows(&self) -> usize {
+        if self.stride == 0 || self.slice.len() < self.len {
+            0
+        } else {
+            (self.slice.len() - self.len) / self.stride + 1
+        }
+    }
+
+    /// Returns a raw-pointer-stepping iterator over column `j`, yielding `&mut T` down the rows.
+    pub fn column_mut(&mut self, j: usize) -> ColumnIterMut<'_, T> {
+        let remaining = if j < self.len { self.num_rows() } else { 0 };
+        let ptr = unsafe { self.slice.as_mut_ptr().add(j.min(self.slice.len())) };
+        ColumnIterMut {
+            ptr,
+            stride: self.stride,
+            remaining,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over all columns, each yielding a [`ColumnIterMut`].
+    ///
+    /// Yields no columns if `len > stride`, since overlapping columns would otherwise hand out
+    /// more than one live `&mut` to the same element (e.g. column `0` and column `stride` share
+    /// flat index `stride`).
+    pub fn columns_mut(&mut self) -> ColumnsMut<'_, T> {
+        let len = if self.len > self.stride { 0 } else { self.len };
+        ColumnsMut {
+            base_ptr: self.slice.as_mut_ptr(),
+            slice_len: self.slice.len(),
+            stride: self.stride,
+            rows: self.num_rows(),
+            len,
+            col: 0,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Cyclically shifts whole rows left by `k`, wrapping rows that fall off the front back to
+    /// the end.
+    pub fn rotate_rows_left(&mut self, k: usize) {
+        let n = self.num_rows();
+        if n == 0 {
+            return;
+        }
+        let k = k % n;
+        if k == 0 {
+            return;
+        }
+        self.reverse_rows(0, k);
+        self.reverse_rows(k, n);
+        self.reverse_rows(0, n);
+    }
+
+    /// Cyclically shifts whole rows right by `k`, wrapping rows that fall off the end back to
+    /// the front.
+    pub fn rotate_rows_right(&mut self, k: usize) {
+        let n = self.num_rows();
+        if n == 0 {
+            return;
+        }
+        let k = k % n;
+        if k == 0 {
+            return;
+        }
+        self.rotate_rows_left(n - k);
+    }
+
+    /// Cyclically shifts whole columns left by `k`, wrapping columns that fall off the front
+    /// back to the end.
+    pub fn rotate_cols_left(&mut self, k: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let k = k % self.len;
+        if k == 0 {
+            return;
+        }
+        self.reverse_cols(0, k);
+        self.reverse_cols(k, self.len);
+        self.reverse_cols(0, self.len);
+    }
+
+    /// Cyclically shifts whole columns right by `k`, wrapping columns that fall off the end
+    /// back to the front.
+    pub fn rotate_cols_right(&mut self, k: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let k = k % self.len;
+        if k == 0 {
+            return;
+        }
+        self.rotate_cols_left(self.len - k);
+    }
+
+    /// Reverses the row order within `[start, end)` by swapping whole row spans.
+    fn reverse_rows(&mut self, mut start: usize, mut end: usize) {
+        while start + 1 < end {
+            end -= 1;
+            self.swap_rows(start, end);
+            start += 1;
+        }
+    }
+
+    /// Swaps the entire `len`-element spans of rows `a` and `b`.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for col in 0..self.len {
+            self.slice.swap(a * self.stride + col, b * self.stride + col);
+        }
+    }
+
+    /// Reverses the column order within `[start, end)` by swapping whole column spans.
+    fn reverse_cols(&mut self, mut start: usize, mut end: usize) {
+        while start + 1 < end {
+            end -= 1;
+            self.swap_cols(start, end);
+            start += 1;
+        }
+    }
+
+    /// Swaps every element of columns `a` and `b` across all rows.
+    fn swap_cols(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let rows = self.num_rows();
+        for row in 0..rows {
+            self.slice.swap(row * self.stride + a, row * self.stride + b);
+        }
+    }
+}
+
+impl<'t, T> Slice2DMut<'t, T> {
+    /// Sorts the rows in place using the given comparator, treating each row as a single
+    /// comparable record.
+    ///
+    /// This is an allocation-free insertion sort over row spans, so it runs in `O(rows^2)`.
+    pub fn sort_rows_by(&mut self, mut cmp: impl FnMut(&[T], &[T]) -> core::cmp::Ordering) {
+        let n = self.num_rows();
+        for i in 1..n {
+            let mut j = i;
+            while j > 0 && cmp(&self[j - 1], &self[j]) == core::cmp::Ordering::Greater {
+                self.swap_rows(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Sorts the rows in place by the key extracted from each row.
+    pub fn sort_rows_by_key<K: Ord>(&mut self, mut f: impl FnMut(&[T]) -> K) {
+        self.sort_rows_by(|a, b| f(a).cmp(&f(b)));
+    }
+}
+
+impl<T: Ord> Slice2DMut<'_, T> {
+    /// Sorts the rows in place, treating each row as a single comparable record.
+    pub fn sort_rows(&mut self) {
+        self.sort_rows_by(|a, b| a.cmp(b));
+    }
+}
+
+/// A raw-pointer-stepping iterator down a single column, created by
+/// [`Slice2DMut::column_mut`] or [`ColumnsMut`].
+pub struct ColumnIterMut<'t, T> {
+    ptr: *mut T,
+    stride: usize,
+    remaining: usize,
+    _marker: core::marker::PhantomData<&'t mut T>,
+}
+
+impl<'t, T> Iterator for ColumnIterMut<'t, T> {
+    type Item = &'t mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `ptr` was derived from the backing slice of the `Slice2DMut` that created
+        // this iterator, and it is only ever dereferenced while `remaining` is nonzero. It is
+        // advanced by `stride` once per item, staying within the bounds of that slice, and
+        // never re-reads an element already handed out.
+        let item = unsafe { &mut *self.ptr };
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.ptr = unsafe { self.ptr.add(self.stride) };
+        }
+        Some(item)
+    }
+}
+
+/// An iterator over all columns, created by [`Slice2DMut::columns_mut`].
+pub struct ColumnsMut<'t, T> {
+    base_ptr: *mut T,
+    slice_len: usize,
+    stride: usize,
+    rows: usize,
+    len: usize,
+    col: usize,
+    _marker: core::marker::PhantomData<&'t mut T>,
+}
+
+impl<'t, T> Iterator for ColumnsMut<'t, T> {
+    type Item = ColumnIterMut<'t, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col >= self.len {
+            return None;
+        }
+        // SAFETY: distinct columns occupy disjoint offsets in the row-major buffer, so handing
+        // out a new `ColumnIterMut` per column, even while earlier ones are still alive, never
+        // produces overlapping mutable references. The offset is clamped to `slice_len` so it
+        // never walks past the end of the backing allocation, matching `column_mut`.
+        let ptr = unsafe { self.base_ptr.add(self.col.min(self.slice_len)) };
+        self.col += 1;
+        Some(ColumnIterMut {
+            ptr,
+            stride: self.stride,
+            remaining: self.rows,
+            _marker: core::marker::PhantomData,
+        })
+    }
 }
 
 impl<T> Index<usize> for Slice2D<'_, T> {
@@ -101,6 +502,89 @@ impl<'t, T> Iterator for Slice2D<'t, T> {
         self.slice = self.slice.get(self.stride..).unwrap_or(&[]);
         result
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rows = self.num_rows();
+        (rows, Some(rows))
+    }
+}
+
+impl<T> ExactSizeIterator for Slice2D<'_, T> {}
+
+impl<'t, T> DoubleEndedIterator for Slice2D<'t, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rows = self.num_rows();
+        if rows == 0 {
+            self.slice = &[];
+            return None;
+        }
+        let start = (rows - 1) * self.stride;
+        let result = self.slice.get(start..start + self.len);
+        self.slice = &self.slice[..start];
+        result
+    }
+}
+
+impl<'t, T> IntoIterator for &Slice2D<'t, T> {
+    type Item = &'t [T];
+    type IntoIter = Slice2D<'t, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Slice2D {
+            stride: self.stride,
+            len: self.len,
+            slice: self.slice,
+        }
+    }
+}
+
+impl<'a, 't, T> IntoIterator for &'a mut Slice2DMut<'t, T> {
+    type Item = &'a mut [T];
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'t, T> Slice2DMut<'t, T> {
+    /// Returns a borrowing iterator over the rows, yielding `&mut [T]` for each complete row.
+    ///
+    /// Yields no rows if `len > stride`, since overlapping rows can't be handed out as
+    /// non-aliasing `&mut [T]`.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            stride: self.stride,
+            len: self.len,
+            slice: self.slice,
+        }
+    }
+}
+
+/// A mutable row iterator created by [`Slice2DMut::iter_mut`].
+pub struct IterMut<'t, T> {
+    stride: usize,
+    len: usize,
+    slice: &'t mut [T],
+}
+
+impl<'t, T> Iterator for IterMut<'t, T> {
+    type Item = &'t mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len > self.stride {
+            self.slice = &mut [];
+            return None;
+        }
+        let slice = core::mem::take(&mut self.slice);
+        if slice.len() < self.len {
+            return None;
+        }
+        let split = self.stride.min(slice.len());
+        let (chunk, rest) = slice.split_at_mut(split);
+        self.slice = rest;
+        Some(&mut chunk[..self.len])
+    }
 }
 
 /// Extension for creating 2 dimensional slices.
@@ -202,4 +686,169 @@ mod test {
         assert_eq!(slice[2][1], 6);
         assert_eq!(&slice[1], &[3, 4]);
     }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut v = [1, 2, 3, 4, 5, 6];
+        let mut slice = v.get_slice2d_mut(3, 2).unwrap();
+        for row in slice.iter_mut() {
+            row.fill(0);
+        }
+        assert_eq!(v, [0, 0, 0, 0, 0, 0]);
+
+        let mut v = [1, 2, 3, 4, 5];
+        let mut slice = v.slice2d_mut(2, 2);
+        let mut rows = slice.iter_mut();
+        assert_eq!(rows.next(), Some(&mut [1, 2][..]));
+        assert_eq!(rows.next(), Some(&mut [3, 4][..]));
+        assert_eq!(rows.next(), None);
+
+        // `len > stride` would make rows overlap; no safe non-aliasing rows can be handed out.
+        let mut v = [1, 2, 3, 4, 5, 6];
+        let mut slice = v.slice2d_mut(3, 2);
+        assert_eq!(slice.iter_mut().next(), None);
+    }
+
+    #[test]
+    fn test_columns() {
+        let v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let slice = v.get_slice2d(3, 3).unwrap();
+        assert!(slice.column(0).eq(&[1, 4, 7]));
+        assert!(slice.column(1).eq(&[2, 5, 8]));
+        assert!(slice.column(2).eq(&[3, 6, 9]));
+        // Out-of-range columns must not leak elements from neighbouring rows.
+        assert_eq!(slice.column(5).count(), 0);
+
+        let expected = [[1, 4, 7], [2, 5, 8], [3, 6, 9]];
+        for (col, expected) in slice.columns().zip(expected) {
+            assert!(col.eq(&expected));
+        }
+
+        let mut v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut slice = v.get_slice2d_mut(3, 3).unwrap();
+        for x in slice.column_mut(1) {
+            *x *= 10;
+        }
+        assert_eq!(v, [1, 20, 3, 4, 50, 6, 7, 80, 9]);
+
+        let mut v = [1, 2, 3, 4, 5, 6];
+        let mut slice = v.get_slice2d_mut(2, 3).unwrap();
+        for col in slice.columns_mut() {
+            for x in col {
+                *x += 1;
+            }
+        }
+        assert_eq!(v, [2, 3, 4, 5, 6, 7]);
+
+        // A slice shorter than one row must not form out-of-bounds pointers while walking
+        // `columns_mut`.
+        let mut v = [1];
+        let mut slice = v.slice2d_mut(3, 3);
+        assert_eq!(slice.columns_mut().count(), 3);
+
+        // `len > stride` would make columns overlap (e.g. column 0 and column `stride` share a
+        // flat index); no safe non-aliasing columns can be handed out.
+        let mut v = [0, 1, 2, 3, 4];
+        let mut slice = v.slice2d_mut(3, 2);
+        assert_eq!(slice.columns_mut().count(), 0);
+    }
+
+    #[test]
+    fn test_crop() {
+        let v = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let slice = v.get_slice2d(3, 4).unwrap();
+        let cropped = slice.crop(1..3, 1..3);
+        assert_eq!(&cropped[0], &[6, 7]);
+        assert_eq!(&cropped[1], &[10, 11]);
+
+        let mut v = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let mut slice = v.get_slice2d_mut(3, 4).unwrap();
+        let mut cropped = slice.crop_mut(1..3, 1..3);
+        cropped[0][0] = 0;
+        cropped[1][1] = 0;
+        assert_eq!(v, [1, 2, 3, 4, 5, 0, 7, 8, 9, 10, 0, 12]);
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let v = [1, 2, 3, 4, 5, 6];
+        let slice = v.get_slice2d(3, 2).unwrap();
+        unsafe {
+            assert_eq!(slice.get_unchecked(1), &[3, 4]);
+            assert_eq!(slice.get_unchecked_elem(2, 1), &6);
+        }
+
+        let mut v = [1, 2, 3, 4, 5, 6];
+        let mut slice = v.get_slice2d_mut(3, 2).unwrap();
+        unsafe {
+            slice.get_unchecked_mut(0).fill(0);
+            *slice.get_unchecked_elem_mut(2, 1) = 9;
+        }
+        assert_eq!(v, [0, 0, 3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut slice = v.get_slice2d_mut(3, 3).unwrap();
+        slice.rotate_rows_left(1);
+        assert_eq!(v, [4, 5, 6, 7, 8, 9, 1, 2, 3]);
+
+        let mut slice = v.get_slice2d_mut(3, 3).unwrap();
+        slice.rotate_rows_right(1);
+        assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut slice = v.get_slice2d_mut(3, 3).unwrap();
+        slice.rotate_cols_left(1);
+        assert_eq!(v, [2, 3, 1, 5, 6, 4, 8, 9, 7]);
+
+        let mut slice = v.get_slice2d_mut(3, 3).unwrap();
+        slice.rotate_cols_right(1);
+        assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_sort_rows() {
+        let mut v = [3, 0, 1, 1, 2, 9, 2, 5];
+        let mut slice = v.get_slice2d_mut(4, 2).unwrap();
+        slice.sort_rows();
+        assert_eq!(v, [1, 1, 2, 5, 2, 9, 3, 0]);
+
+        let mut v = [1, 0, 0, 3, 0, 0, 2, 0, 0];
+        let mut slice = v.get_slice2d_mut(3, 3).unwrap();
+        slice.sort_rows_by_key(|row| core::cmp::Reverse(row[0]));
+        assert_eq!(v, [3, 0, 0, 2, 0, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_iterator_contract() {
+        let v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let slice = v.get_slice2d(3, 3).unwrap();
+        assert_eq!(slice.size_hint(), (3, Some(3)));
+        assert_eq!(slice.len(), 3);
+
+        let mut slice = v.get_slice2d(3, 3).unwrap();
+        assert_eq!(slice.next_back(), Some(&[7, 8, 9][..]));
+        assert_eq!(slice.next_back(), Some(&[4, 5, 6][..]));
+        assert_eq!(slice.next_back(), Some(&[1, 2, 3][..]));
+        assert_eq!(slice.next_back(), None);
+
+        let slice = v.get_slice2d(3, 3).unwrap();
+        let mut rows: [&[i32]; 3] = [&[], &[], &[]];
+        for (dst, row) in rows.iter_mut().zip(&slice) {
+            *dst = row;
+        }
+        assert_eq!(rows, [&[1, 2, 3][..], &[4, 5, 6][..], &[7, 8, 9][..]]);
+
+        let mut v = [1, 2, 3, 4, 5, 6];
+        let mut slice = v.get_slice2d_mut(3, 2).unwrap();
+        for row in &mut slice {
+            row.fill(0);
+        }
+        assert_eq!(v, [0, 0, 0, 0, 0, 0]);
+
+        // `stride == 0` must not panic on division; `size_hint` reports no rows.
+        let v = [1, 2];
+        assert_eq!(v.slice2d(2, 0).size_hint(), (0, Some(0)));
+    }
 }